@@ -0,0 +1,78 @@
+//! Multi-thread-safe JNI helpers, modelled on the `JavaVM`-caching pattern
+//! DuckStation's `android_host_interface.cpp` uses: stash the `JavaVM*` at
+//! `JNI_OnLoad` time so any thread — not just the one a JNI call happened to
+//! arrive on — can get a valid `JNIEnv` by attaching itself first.
+use ::jni::{AttachGuard, JNIEnv, JavaVM, objects::JValue, sys::jint};
+use once_cell::sync::OnceCell;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_void;
+
+static JAVA_VM: OnceCell<JavaVM> = OnceCell::new();
+
+/// Called by the JVM when the native library is loaded; caches the
+/// `JavaVM*` so [`get_env`] can attach arbitrary threads later.
+#[unsafe(no_mangle)]
+pub extern "system" fn JNI_OnLoad(vm: JavaVM, _reserved: *mut c_void) -> jint {
+	JAVA_VM.set(vm).ok();
+	::jni::sys::JNI_VERSION_1_6
+}
+
+/// A `JNIEnv` handed back by [`get_env`]: either the one the calling thread
+/// already had (`Borrowed`), or one obtained by attaching the thread just now
+/// (`Attached`). `Attached` detaches the thread on drop via `AttachGuard`, so
+/// callers don't need to care which case they got.
+pub enum EnvHandle<'a> {
+	Borrowed(JNIEnv<'a>),
+	Attached(AttachGuard<'a>),
+}
+
+impl<'a> Deref for EnvHandle<'a> {
+	type Target = JNIEnv<'a>;
+
+	fn deref(&self) -> &Self::Target {
+		match self {
+			EnvHandle::Borrowed(env) => env,
+			EnvHandle::Attached(guard) => guard,
+		}
+	}
+}
+
+impl<'a> DerefMut for EnvHandle<'a> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		match self {
+			EnvHandle::Borrowed(env) => env,
+			EnvHandle::Attached(guard) => guard,
+		}
+	}
+}
+
+/// Returns a `JNIEnv` valid for the calling thread, attaching the thread to
+/// the JVM first if it isn't already attached. If this call is the one that
+/// attached the thread, it is detached again (via `AttachGuard`'s `Drop`)
+/// once the returned handle goes out of scope, matching
+/// `AttachCurrentThread`/`DetachCurrentThread` pairing.
+pub fn get_env() -> Option<EnvHandle<'static>> {
+	let vm = JAVA_VM.get()?;
+	match vm.get_env() {
+		Ok(env) => Some(EnvHandle::Borrowed(env)),
+		Err(_) => vm.attach_current_thread().ok().map(EnvHandle::Attached),
+	}
+}
+
+/// Calls a `void` *static* method by class/method/signature, from whichever
+/// thread happens to be calling. Used to push navigation state up to the
+/// Kotlin side (e.g. `onNavigationChanged`) without threading a `JNIEnv`
+/// through Rust callbacks that may run off the UI thread.
+pub fn call_java_void(
+	class: &str,
+	method: &str,
+	sig: &str,
+	args: &[JValue],
+) -> Result<(), ::jni::errors::Error> {
+	let Some(mut env) = get_env() else {
+		return Err(::jni::errors::Error::JavaException);
+	};
+	let class = env.find_class(class)?;
+	env.call_static_method(class, method, sig, args)?;
+	Ok(())
+}
@@ -1,5 +1,6 @@
 slint::include_modules!();
 
+mod jni;
 mod navigation_handler;
 
 #[cfg(target_os = "android")]
@@ -17,12 +18,23 @@ pub fn android_main(app: slint::android::AndroidApp) {
 	let app_weak_nav = app_weak.clone();
 	app.global::<PageNavigator>().on_navigate_to(move |page| {
 		println!("UI Navigate to: {:?}", page);
-		navigation_handler::push_page(page.clone());
-		app_weak_nav
-			.upgrade()
-			.unwrap()
-			.global::<PageNavigator>()
-			.set_current_page(page);
+		let from = navigation_handler::current_page().unwrap_or(page.clone());
+		let target = match navigation_handler::run_navigation_throttles(from, page.clone()) {
+			navigation_handler::ThrottleDecision::Proceed => Some(page),
+			navigation_handler::ThrottleDecision::Redirect(redirected) => Some(redirected),
+			navigation_handler::ThrottleDecision::Cancel => {
+				println!("UI Navigate to: cancelled by throttle");
+				None
+			}
+		};
+		if let Some(target) = target {
+			navigation_handler::push_page(target.clone());
+			let navigator = app_weak_nav.upgrade().unwrap();
+			navigator.global::<PageNavigator>().set_current_page(target);
+			navigator
+				.global::<PageNavigator>()
+				.set_current_params(navigation_handler::current_params_json());
+		}
 	});
 
 	// Handle back from UI
@@ -33,14 +45,27 @@ pub fn android_main(app: slint::android::AndroidApp) {
 		// Or we use current_page helper.
 		if navigation_handler::pop_page() {
 			if let Some(top) = navigation_handler::current_page() {
-				app_weak_back
-					.upgrade()
-					.unwrap()
+				let navigator = app_weak_back.upgrade().unwrap();
+				navigator.global::<PageNavigator>().set_current_page(top);
+				navigator
 					.global::<PageNavigator>()
-					.set_current_page(top);
+					.set_current_params(navigation_handler::current_params_json());
 			}
 		}
 	});
 
+	// Handle forward from UI
+	let app_weak_forward = app_weak.clone();
+	app.global::<PageNavigator>().on_navigate_forward(move || {
+		println!("UI Navigate forward");
+		if let Some(top) = navigation_handler::go(1) {
+			let navigator = app_weak_forward.upgrade().unwrap();
+			navigator.global::<PageNavigator>().set_current_page(top);
+			navigator
+				.global::<PageNavigator>()
+				.set_current_params(navigation_handler::current_params_json());
+		}
+	});
+
 	app.run().unwrap();
 }
@@ -1,57 +1,586 @@
-use jni::{JNIEnv, objects::JClass, sys::jboolean};
+use jni::{
+	JNIEnv,
+	objects::{JClass, JString, JValue},
+	sys::{jboolean, jbyteArray},
+};
 use once_cell::sync::OnceCell;
-use slint::{ComponentHandle, Weak};
+use slint::{ComponentHandle, SharedString, Weak};
+use std::collections::HashMap;
 use std::sync::Mutex;
 
 use crate::{AppWindow, PageNavigator, Pages};
 
+/// A single entry in the back/forward list: the page identity plus whatever
+/// route parameters it was navigated to with (e.g. an item id for a detail
+/// page), mirroring how Chromium's `NavigationEntry` carries per-entry state
+/// alongside the page/URL.
+#[derive(Clone)]
+pub struct NavEntry {
+	pub page: Pages,
+	pub args: HashMap<String, String>,
+}
+
+impl NavEntry {
+	fn new(page: Pages) -> Self {
+		Self {
+			page,
+			args: HashMap::new(),
+		}
+	}
+}
+
+/// Encodes a params map as a flat JSON object of string keys/values, since
+/// that's all `current_params` ever needs to carry.
+fn encode_params(args: &HashMap<String, String>) -> SharedString {
+	let mut json = String::from("{");
+	for (i, (key, value)) in args.iter().enumerate() {
+		if i > 0 {
+			json.push(',');
+		}
+		json.push_str(&format!("{}:{}", json_string(key), json_string(value)));
+	}
+	json.push('}');
+	json.into()
+}
+
+/// Parses a `key=value&key2=value2` query string into a params map. Missing
+/// `=` just yields an empty-string value for that key.
+fn parse_query(query: &str) -> HashMap<String, String> {
+	query
+		.split('&')
+		.filter(|pair| !pair.is_empty())
+		.map(|pair| match pair.split_once('=') {
+			Some((key, value)) => (key.to_string(), value.to_string()),
+			None => (pair.to_string(), String::new()),
+		})
+		.collect()
+}
+
+fn json_string(s: &str) -> String {
+	let mut escaped = String::with_capacity(s.len() + 2);
+	escaped.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			_ => escaped.push(c),
+		}
+	}
+	escaped.push('"');
+	escaped
+}
+
+/// Inverse of [`encode_params`]: parses the flat JSON object produced there
+/// back into a params map. Malformed input just yields whatever prefix of
+/// key/value pairs parsed cleanly.
+fn decode_params(json: &str) -> HashMap<String, String> {
+	let mut map = HashMap::new();
+	let body = json
+		.trim()
+		.strip_prefix('{')
+		.and_then(|s| s.strip_suffix('}'))
+		.unwrap_or("");
+
+	let mut rest = body;
+	loop {
+		rest = rest.trim_start().trim_start_matches(',').trim_start();
+		if rest.is_empty() {
+			break;
+		}
+		let Some((key, after)) = parse_json_string(rest) else {
+			break;
+		};
+		let Some(after) = after.trim_start().strip_prefix(':') else {
+			break;
+		};
+		let Some((value, after)) = parse_json_string(after.trim_start()) else {
+			break;
+		};
+		map.insert(key, value);
+		rest = after;
+	}
+	map
+}
+
+/// Parses one `"..."` JSON string (with `\"`/`\\` escapes, matching
+/// [`json_string`]'s encoding) from the start of `s`, returning the decoded
+/// value and the remainder of `s` after the closing quote.
+fn parse_json_string(s: &str) -> Option<(String, &str)> {
+	let rest = s.strip_prefix('"')?;
+	let mut decoded = String::new();
+	let mut chars = rest.char_indices();
+	while let Some((i, c)) = chars.next() {
+		match c {
+			'"' => return Some((decoded, &rest[i + 1..])),
+			'\\' => {
+				let (_, escaped) = chars.next()?;
+				match escaped {
+					'"' => decoded.push('"'),
+					'\\' => decoded.push('\\'),
+					other => decoded.push(other),
+				}
+			}
+			other => decoded.push(other),
+		}
+	}
+	None
+}
+
+/// Stable wire tag for a `Pages` variant. These values are what gets written
+/// to `onSaveInstanceState`, so they must never be reassigned once shipped —
+/// reordering or renaming `Pages` variants in the Slint UI must not change
+/// what a saved tag means.
+fn page_to_tag(page: &Pages) -> u8 {
+	match page {
+		Pages::COUNTER => 0,
+		Pages::SETTINGS => 1,
+		Pages::PROFILE => 2,
+	}
+}
+
+fn tag_to_page(tag: u8) -> Option<Pages> {
+	match tag {
+		0 => Some(Pages::COUNTER),
+		1 => Some(Pages::SETTINGS),
+		2 => Some(Pages::PROFILE),
+		_ => None,
+	}
+}
+
+// A Chromium-NavigationController-style back/forward list: every page we've
+// ever visited stays in `entries`, and `current_index` is just a cursor into
+// it. Going back/forward moves the cursor; navigating to a new page truncates
+// anything ahead of the cursor first.
+struct NavigationStack {
+	entries: Vec<NavEntry>,
+	current_index: usize,
+}
+
+impl NavigationStack {
+	fn new(initial: Pages) -> Self {
+		Self {
+			entries: vec![NavEntry::new(initial)],
+			current_index: 0,
+		}
+	}
+
+	fn push(&mut self, entry: NavEntry) {
+		self.entries.truncate(self.current_index + 1);
+		self.entries.push(entry);
+		self.current_index = self.entries.len() - 1;
+	}
+
+	fn pop(&mut self) -> bool {
+		if self.current_index > 0 {
+			self.current_index -= 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn go(&mut self, delta: i32) -> Pages {
+		let max_index = self.entries.len() - 1;
+		let target = (self.current_index as i64 + delta as i64).clamp(0, max_index as i64);
+		self.current_index = target as usize;
+		self.current().page.clone()
+	}
+
+	fn current(&self) -> &NavEntry {
+		&self.entries[self.current_index]
+	}
+
+	fn can_go_back(&self) -> bool {
+		self.current_index > 0
+	}
+
+	fn can_go_forward(&self) -> bool {
+		self.current_index + 1 < self.entries.len()
+	}
+}
+
+/// Decision a registered throttle can make about an in-flight navigation,
+/// modelled after Chromium's `NavigationThrottle::ThrottleAction`.
+pub enum ThrottleDecision {
+	/// Let the navigation continue to `to` unchanged.
+	Proceed,
+	/// Abort the navigation; `current_page` stays where it was.
+	Cancel,
+	/// Continue the navigation, but to this page instead of the requested one.
+	Redirect(Pages),
+}
+
+type NavigationThrottle = dyn Fn(Pages, Pages) -> ThrottleDecision + Send;
+
+/// Lifecycle event fired around a navigation-stack mutation, modelled after
+/// Chromium's `NavigationObserver` callbacks. Gives the app a single place to
+/// hook analytics, logging, or screen-specific setup/teardown instead of
+/// threading callbacks through every `on_navigate_*` closure.
+pub enum NavEvent {
+	/// A new entry was pushed on top of the stack.
+	Pushed(Pages),
+	/// The cursor moved back to this page (stack unchanged, just the index).
+	Popped(Pages),
+	/// The stack was replaced wholesale, e.g. from `restore_stack`.
+	Restored(Pages),
+}
+
+type NavigationObserver = dyn Fn(&NavEvent) + Send;
+
 // Global stack
-static NAVIGATION_STACK: OnceCell<Mutex<Vec<Pages>>> = OnceCell::new();
+static NAVIGATION_STACK: OnceCell<Mutex<NavigationStack>> = OnceCell::new();
 // Global app handle
 static APP_HANDLE: OnceCell<Weak<AppWindow>> = OnceCell::new();
+// Throttles run, in registration order, before every `push_page` that goes
+// through `on_navigate_to`.
+static NAVIGATION_THROTTLES: OnceCell<Mutex<Vec<Box<NavigationThrottle>>>> = OnceCell::new();
+// Observers are notified, in registration order, after every push, pop, or
+// wholesale replacement of the stack; `go` moves the cursor without notifying
+// them.
+static NAVIGATION_OBSERVERS: OnceCell<Mutex<Vec<Box<NavigationObserver>>>> = OnceCell::new();
+// Deep-link route table: normalized "a/b/c" path -> the page it resolves to.
+static ROUTE_TABLE: OnceCell<Mutex<HashMap<String, Pages>>> = OnceCell::new();
+
+fn normalize_route(path: &str) -> String {
+	path.trim_matches('/').to_string()
+}
+
+/// Registers a deep-link route, e.g. `register_route("settings/profile",
+/// Pages::PROFILE)`, so [`resolve_deep_link`] can turn
+/// `myapp://settings/profile` into a navigation to `Pages::PROFILE`.
+pub fn register_route(path: &str, page: Pages) {
+	ROUTE_TABLE
+		.get_or_init(|| Mutex::new(HashMap::new()))
+		.lock()
+		.unwrap()
+		.insert(normalize_route(path), page);
+}
+
+/// Parses a `scheme://host/path?query` deep link into the back-stack that
+/// should be installed so `Back` behaves naturally: every registered prefix
+/// of the path becomes an intermediate entry (e.g. `settings` then
+/// `settings/profile`), always rooted at `Pages::COUNTER`. Query parameters
+/// are attached only to the final (innermost) entry. Returns `None` if no
+/// registered route matches any prefix of the path.
+pub fn resolve_deep_link(uri: &str) -> Option<Vec<NavEntry>> {
+	let without_scheme = uri.split_once("://").map(|(_, rest)| rest).unwrap_or(uri);
+	let (path_part, query) = match without_scheme.split_once('?') {
+		Some((path, query)) => (path, query),
+		None => (without_scheme, ""),
+	};
+	let args = parse_query(query);
+
+	let segments: Vec<&str> = path_part.split('/').filter(|s| !s.is_empty()).collect();
+	if segments.is_empty() {
+		return None;
+	}
+
+	let table = ROUTE_TABLE.get()?.lock().ok()?;
+	let mut entries = vec![NavEntry::new(Pages::COUNTER)];
+	for depth in 1..=segments.len() {
+		let prefix = segments[..depth].join("/");
+		if let Some(page) = table.get(&prefix) {
+			entries.push(NavEntry::new(page.clone()));
+		}
+	}
+
+	if entries.len() == 1 {
+		// No registered route matched any prefix of the path.
+		return None;
+	}
+	if let Some(last) = entries.last_mut() {
+		last.args = args;
+	}
+	Some(entries)
+}
+
+/// Registers an observer notified after a push, a pop, or a wholesale
+/// replacement of the navigation stack has committed; [`go`] moves the cursor
+/// without notifying observers. Observers run in registration order and cannot
+/// veto or alter the navigation — use [`register_navigation_throttle`] for that.
+pub fn add_navigation_observer(f: impl Fn(&NavEvent) + Send + 'static) {
+	NAVIGATION_OBSERVERS
+		.get_or_init(|| Mutex::new(Vec::new()))
+		.lock()
+		.unwrap()
+		.push(Box::new(f));
+}
+
+fn notify_navigation_observers(event: NavEvent) {
+	if let Some(mutex) = NAVIGATION_OBSERVERS.get() {
+		for observer in mutex.lock().unwrap().iter() {
+			observer(&event);
+		}
+	}
+}
+
+/// Registers a throttle that gets a chance to cancel or redirect every
+/// navigation before it commits. Throttles run in registration order; the
+/// first one that returns `Cancel` or `Redirect` wins and the rest are
+/// skipped.
+pub fn register_navigation_throttle(f: impl Fn(Pages, Pages) -> ThrottleDecision + Send + 'static) {
+	NAVIGATION_THROTTLES
+		.get_or_init(|| Mutex::new(Vec::new()))
+		.lock()
+		.unwrap()
+		.push(Box::new(f));
+}
+
+/// Runs the registered throttles for a `from -> to` transition and returns
+/// the final decision: the first non-`Proceed` verdict short-circuits the
+/// rest.
+pub fn run_navigation_throttles(from: Pages, to: Pages) -> ThrottleDecision {
+	if let Some(mutex) = NAVIGATION_THROTTLES.get() {
+		for throttle in mutex.lock().unwrap().iter() {
+			match throttle(from.clone(), to.clone()) {
+				ThrottleDecision::Proceed => continue,
+				decision => return decision,
+			}
+		}
+	}
+	ThrottleDecision::Proceed
+}
 
 pub fn init_navigation_state(handle: Weak<AppWindow>) {
 	APP_HANDLE.set(handle).ok();
 	// Initialize stack with initial page (Counter)
-	let stack = vec![Pages::COUNTER];
-	NAVIGATION_STACK.set(Mutex::new(stack)).ok();
+	NAVIGATION_STACK
+		.set(Mutex::new(NavigationStack::new(Pages::COUNTER)))
+		.ok();
+
+	// Mirror every navigation event up to the Kotlin side through the JNI
+	// helper layer, so `Activity`-level code (e.g. updating the toolbar
+	// title) doesn't need a Rust callback wired in for it separately.
+	add_navigation_observer(|event| {
+		let page = match event {
+			NavEvent::Pushed(page) | NavEvent::Popped(page) | NavEvent::Restored(page) => page,
+		};
+		let tag = page_to_tag(page) as i32;
+		if let Err(err) = crate::jni::call_java_void(
+			"slint/router/JNINavigationHandler",
+			"onNavigationChanged",
+			"(I)V",
+			&[JValue::Int(tag)],
+		) {
+			println!("JNI: onNavigationChanged call failed: {:?}", err);
+		}
+	});
 }
 
 pub fn push_page(page: Pages) {
-	if let Some(mutex) = NAVIGATION_STACK.get() {
+	push_page_with_args(page, HashMap::new());
+}
+
+/// Pushes a new entry carrying route parameters (e.g. `{"id": "42"}` for an
+/// item-detail page), clearing any forward history exactly like `push_page`.
+pub fn push_page_with_args(page: Pages, args: HashMap<String, String>) {
+	let pushed = if let Some(mutex) = NAVIGATION_STACK.get() {
 		if let Ok(mut stack) = mutex.lock() {
-			stack.push(page);
+			stack.push(NavEntry {
+				page: page.clone(),
+				args,
+			});
 			println!(
 				"Rust Stack: Push {:?}, Depth: {}",
-				stack.last(),
-				stack.len()
+				stack.current().page,
+				stack.entries.len()
 			);
+			true
+		} else {
+			false
 		}
+	} else {
+		false
+	};
+
+	if pushed {
+		notify_navigation_observers(NavEvent::Pushed(page));
 	}
 }
 
 pub fn pop_page() -> bool {
-	if let Some(mutex) = NAVIGATION_STACK.get() {
+	let popped_to = if let Some(mutex) = NAVIGATION_STACK.get() {
 		if let Ok(mut stack) = mutex.lock() {
-			if stack.len() > 1 {
-				stack.pop();
-				println!("Rust Stack: Pop, New Depth: {}", stack.len());
-				return true;
+			if stack.pop() {
+				println!("Rust Stack: Pop, New Depth: {}", stack.current_index + 1);
+				Some(stack.current().page.clone())
+			} else {
+				None
 			}
+		} else {
+			None
+		}
+	} else {
+		None
+	};
+
+	match popped_to {
+		Some(page) => {
+			notify_navigation_observers(NavEvent::Popped(page));
+			true
+		}
+		None => false,
+	}
+}
+
+/// Moves the navigation cursor by `delta` entries, clamping into the valid
+/// range, and returns the page now at the top of the stack. A positive delta
+/// goes forward, a negative delta goes back; `go(0)` is a no-op that just
+/// returns the current page.
+pub fn go(delta: i32) -> Option<Pages> {
+	if let Some(mutex) = NAVIGATION_STACK.get() {
+		if let Ok(mut stack) = mutex.lock() {
+			let page = stack.go(delta);
+			println!("Rust Stack: Go {}, New Index: {}", delta, stack.current_index);
+			return Some(page);
+		}
+	}
+	None
+}
+
+/// Replaces the whole back/forward list wholesale (e.g. from a resolved deep
+/// link), putting the cursor on the last entry. Fires a `Restored` event
+/// rather than `Pushed`, since this isn't a normal forward navigation.
+pub fn install_stack(entries: Vec<NavEntry>) {
+	if entries.is_empty() {
+		return;
+	}
+	let installed_to = if let Some(mutex) = NAVIGATION_STACK.get() {
+		if let Ok(mut stack) = mutex.lock() {
+			stack.current_index = entries.len() - 1;
+			let top = entries[stack.current_index].page.clone();
+			stack.entries = entries;
+			Some(top)
+		} else {
+			None
 		}
+	} else {
+		None
+	};
+
+	if let Some(page) = installed_to {
+		notify_navigation_observers(NavEvent::Restored(page));
 	}
-	false
+}
+
+pub fn can_go_back() -> bool {
+	NAVIGATION_STACK
+		.get()
+		.and_then(|mutex| mutex.lock().ok())
+		.map(|stack| stack.can_go_back())
+		.unwrap_or(false)
+}
+
+pub fn can_go_forward() -> bool {
+	NAVIGATION_STACK
+		.get()
+		.and_then(|mutex| mutex.lock().ok())
+		.map(|stack| stack.can_go_forward())
+		.unwrap_or(false)
 }
 
 pub fn current_page() -> Option<Pages> {
 	if let Some(mutex) = NAVIGATION_STACK.get() {
 		if let Ok(stack) = mutex.lock() {
-			return stack.last().cloned();
+			return Some(stack.current().page.clone());
 		}
 	}
 	None
 }
 
+/// Route parameters attached to the entry currently on top of the stack.
+pub fn current_args() -> HashMap<String, String> {
+	NAVIGATION_STACK
+		.get()
+		.and_then(|mutex| mutex.lock().ok())
+		.map(|stack| stack.current().args.clone())
+		.unwrap_or_default()
+}
+
+/// JSON-encoded params for the entry currently on top of the stack, suitable
+/// for setting on `PageNavigator.current_params`.
+pub fn current_params_json() -> SharedString {
+	encode_params(&current_args())
+}
+
+/// Encodes the full back/forward list as `[current_index: u32 LE][count: u32
+/// LE][tag: u8; count][per-entry: args_len: u32 LE, args_json: u8; args_len]`,
+/// suitable for handing to `onSaveInstanceState`. Route params (added on top
+/// of the plain page tag by the `NavEntry` work) are carried as the same
+/// JSON encoding `current_params` uses, so a detail page's id survives
+/// process death along with the page it belongs to.
+pub fn serialize_stack() -> Vec<u8> {
+	let mut bytes = Vec::new();
+	if let Some(mutex) = NAVIGATION_STACK.get() {
+		if let Ok(stack) = mutex.lock() {
+			bytes.extend_from_slice(&(stack.current_index as u32).to_le_bytes());
+			bytes.extend_from_slice(&(stack.entries.len() as u32).to_le_bytes());
+			bytes.extend(stack.entries.iter().map(|entry| page_to_tag(&entry.page)));
+			for entry in &stack.entries {
+				let params = encode_params(&entry.args);
+				let params_bytes = params.as_bytes();
+				bytes.extend_from_slice(&(params_bytes.len() as u32).to_le_bytes());
+				bytes.extend_from_slice(params_bytes);
+			}
+		}
+	}
+	bytes
+}
+
+/// Restores the back/forward list from bytes produced by [`serialize_stack`].
+/// Malformed or empty input is ignored and leaves the current stack alone.
+pub fn restore_stack(bytes: &[u8]) {
+	if bytes.len() < 8 {
+		return;
+	}
+	let current_index = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize;
+	let count = u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize;
+	if bytes.len() < 8 + count || count == 0 || current_index >= count {
+		return;
+	}
+	let pages: Vec<Pages> = bytes[8..8 + count].iter().filter_map(|&tag| tag_to_page(tag)).collect();
+	if pages.len() != count {
+		return;
+	}
+
+	let mut cursor = 8 + count;
+	let mut entries = Vec::with_capacity(count);
+	for page in pages {
+		if bytes.len() < cursor + 4 {
+			return;
+		}
+		let params_len = u32::from_le_bytes(bytes[cursor..cursor + 4].try_into().unwrap()) as usize;
+		cursor += 4;
+		if bytes.len() < cursor + params_len {
+			return;
+		}
+		let Ok(params_json) = std::str::from_utf8(&bytes[cursor..cursor + params_len]) else {
+			return;
+		};
+		cursor += params_len;
+		entries.push(NavEntry {
+			page,
+			args: decode_params(params_json),
+		});
+	}
+
+	let restored_to = if let Some(mutex) = NAVIGATION_STACK.get() {
+		if let Ok(mut stack) = mutex.lock() {
+			stack.entries = entries;
+			stack.current_index = current_index;
+			Some(stack.current().page.clone())
+		} else {
+			None
+		}
+	} else {
+		None
+	};
+
+	if let Some(page) = restored_to {
+		notify_navigation_observers(NavEvent::Restored(page));
+	}
+}
+
 // JNI Implementation
 #[unsafe(no_mangle)]
 pub extern "C" fn Java_slint_router_JNINavigationHandler_exitOnBack(
@@ -59,33 +588,121 @@ pub extern "C" fn Java_slint_router_JNINavigationHandler_exitOnBack(
 	_class: JClass,
 ) -> jboolean {
 	println!("JNI: exitOnBack called");
-	let should_exit = if let Some(mutex) = NAVIGATION_STACK.get() {
+	// Compute the pop result with the lock held, then drop the guard before
+	// touching the UI or notifying observers — both can call back into
+	// navigation_handler (e.g. `current_page()`), which would deadlock on
+	// this same non-reentrant mutex if the guard were still alive.
+	let popped_to = if let Some(mutex) = NAVIGATION_STACK.get() {
 		let mut stack = mutex.lock().unwrap();
-		if stack.len() > 1 {
-			stack.pop();
-			let new_top = stack.last().unwrap().clone();
-			println!("JNI: Popping stack, returning to {:?}", new_top);
-
-			// Trigger UI update
-			if let Some(handle) = APP_HANDLE.get() {
-				let handle_copy = handle.clone();
-				let page_copy = new_top.clone();
-				slint::invoke_from_event_loop(move || {
-					if let Some(app) = handle_copy.upgrade() {
-						app.global::<PageNavigator>().set_current_page(page_copy);
-					}
-				})
-				.unwrap();
-			}
-			false
+		if stack.pop() {
+			Some((stack.current().page.clone(), encode_params(&stack.current().args)))
 		} else {
-			println!("JNI: Stack empty or at root, exiting app");
-			true
+			None
 		}
 	} else {
 		println!("JNI: Navigation stack not initialized, exiting");
-		true
+		return true as jboolean;
 	};
 
-	should_exit as jboolean
+	let Some((new_top, new_params)) = popped_to else {
+		println!("JNI: Stack empty or at root, exiting app");
+		return true as jboolean;
+	};
+	println!("JNI: Popping stack, returning to {:?}", new_top);
+
+	// Trigger UI update
+	if let Some(handle) = APP_HANDLE.get() {
+		let handle_copy = handle.clone();
+		let page_copy = new_top.clone();
+		slint::invoke_from_event_loop(move || {
+			if let Some(app) = handle_copy.upgrade() {
+				app.global::<PageNavigator>().set_current_page(page_copy);
+				app.global::<PageNavigator>().set_current_params(new_params);
+			}
+		})
+		.unwrap();
+	}
+	notify_navigation_observers(NavEvent::Popped(new_top));
+
+	false as jboolean
+}
+
+/// Called from `Activity.onSaveInstanceState` to snapshot the navigation
+/// stack before Android may kill the process. Fetches its own `JNIEnv`
+/// through [`crate::jni::get_env`] rather than the one the JVM handed us,
+/// since this can be invoked off the thread that owns that env.
+#[unsafe(no_mangle)]
+pub extern "C" fn Java_slint_router_JNINavigationHandler_saveState(
+	_env: JNIEnv,
+	_class: JClass,
+) -> jbyteArray {
+	println!("JNI: saveState called");
+	let bytes = serialize_stack();
+	let Some(mut env) = crate::jni::get_env() else {
+		return std::ptr::null_mut();
+	};
+	env.byte_array_from_slice(&bytes)
+		.unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Called from `Activity.onCreate` with the bytes previously returned by
+/// `saveState` to restore the navigation stack after process recreation.
+#[unsafe(no_mangle)]
+pub extern "C" fn Java_slint_router_JNINavigationHandler_restoreState(
+	_env: JNIEnv,
+	_class: JClass,
+	state: jbyteArray,
+) {
+	println!("JNI: restoreState called");
+	let Some(mut env) = crate::jni::get_env() else {
+		return;
+	};
+	let Ok(bytes) = env.convert_byte_array(state) else {
+		return;
+	};
+	restore_stack(&bytes);
+	sync_ui_to_current();
+}
+
+/// Pushes `current_page`/`current_params` onto the UI from whatever thread
+/// is calling, via the same `invoke_from_event_loop` hop `exitOnBack` uses.
+fn sync_ui_to_current() {
+	if let (Some(handle), Some(top)) = (APP_HANDLE.get(), current_page()) {
+		let handle_copy = handle.clone();
+		let params = current_params_json();
+		slint::invoke_from_event_loop(move || {
+			if let Some(app) = handle_copy.upgrade() {
+				app.global::<PageNavigator>().set_current_page(top);
+				app.global::<PageNavigator>().set_current_params(params);
+			}
+		})
+		.unwrap();
+	}
+}
+
+/// Called from `Activity.onNewIntent`/`onCreate` with the launching intent's
+/// URI, e.g. `myapp://settings/profile?id=7`. Resolves it via
+/// [`resolve_deep_link`] and, on a match, replaces the current stack and
+/// syncs the UI; on no match the existing stack is left untouched.
+#[unsafe(no_mangle)]
+pub extern "C" fn Java_slint_router_JNINavigationHandler_handleDeepLink(
+	_env: JNIEnv,
+	_class: JClass,
+	uri: JString,
+) {
+	println!("JNI: handleDeepLink called");
+	let Some(mut env) = crate::jni::get_env() else {
+		return;
+	};
+	let Ok(uri): Result<String, _> = env.get_string(&uri).map(Into::into) else {
+		return;
+	};
+
+	match resolve_deep_link(&uri) {
+		Some(entries) => {
+			install_stack(entries);
+			sync_ui_to_current();
+		}
+		None => println!("JNI: handleDeepLink: no route matched {:?}", uri),
+	}
 }